@@ -0,0 +1,164 @@
+use std::cell::{Cell, Ref, RefCell};
+
+/// Interior-mutability flavour of [`Modified`](crate::Modified) that records
+/// changes through shared `&self` references instead of `&mut self`.
+///
+/// This lets the tracker live behind an [`Rc`](std::rc::Rc) or be shared among
+/// aliasing readers while still detecting writes, which the [`DerefMut`]-based
+/// [`Modified`](crate::Modified) cannot do because it requires unique access.
+///
+/// [`DerefMut`]: std::ops::DerefMut
+pub struct ModifiedCell<T>(pub(crate) RefCell<T>, pub(crate) Cell<bool>);
+
+impl<T> ModifiedCell<T> {
+    /// Creates new [`ModifiedCell`] with `v` inside.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// assert_eq!(m.get(), 15);
+    /// ```
+    #[inline]
+    pub fn new(v: T) -> Self {
+        Self(RefCell::new(v), Cell::new(false))
+    }
+
+    /// Creates new [`ModifiedCell`] with `v` inside and marks it as it was modified.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new_modified(15);
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn new_modified(v: T) -> Self {
+        Self(RefCell::new(v), Cell::new(true))
+    }
+
+    /// Replaces the inner value with `v` through a shared reference, marking it as modified.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// m.set(20);
+    /// assert_eq!(m.get(), 20);
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn set(&self, v: T) {
+        *self.0.borrow_mut() = v;
+        self.1.set(true);
+    }
+
+    /// Applies `f` to the inner value through a shared reference, marking it as modified.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// m.with_mut(|v| *v += 5);
+    /// assert_eq!(m.get(), 20);
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn with_mut(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.0.borrow_mut());
+        self.1.set(true);
+    }
+
+    /// Borrows the inner value for reading without marking it as modified.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// assert_eq!(*m.borrow(), 15);
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.0.borrow()
+    }
+
+    /// Returns `true` if the value was modified, otherwise returns `false`.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// m.set(20);
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        self.1.get()
+    }
+
+    /// Returns `true` if the value wasn't changed, otherwise returns `false`.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn is_unchanged(&self) -> bool {
+        !self.1.get()
+    }
+
+    /// Resets internal state.
+    /// If value was marked as modified it no longer is!
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// m.set(20);
+    /// m.reset();
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn reset(&self) {
+        self.1.set(false);
+    }
+
+    /// Returns the ownership of the inner value.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Owned(i32);
+    /// let m = ModifiedCell::new(Owned(15));
+    /// assert_eq!(m.into_inner(), Owned(15));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+impl<T> ModifiedCell<T>
+where
+    T: Copy
+{
+    /// Returns a copy of the inner value without marking it as modified.
+    /// ```
+    /// # use modified::ModifiedCell;
+    /// let m = ModifiedCell::new(15);
+    /// assert_eq!(m.get(), 15);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> T {
+        *self.0.borrow()
+    }
+}
+
+impl<T> Default for ModifiedCell<T>
+where
+    T: Default
+{
+    /// Creates new [`ModifiedCell`] from default value of `T`.
+    #[inline]
+    fn default() -> Self {
+        Self(RefCell::new(T::default()), Cell::new(false))
+    }
+}
+
+impl<T> Clone for ModifiedCell<T>
+where
+    T: Clone
+{
+    /// Clones inner value with it's state.
+    /// That means that if value was changed, cloned will also be marked as changed.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(RefCell::new(self.0.borrow().clone()), self.1.clone())
+    }
+}
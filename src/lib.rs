@@ -14,3 +14,6 @@ pub use reset::*;
 
 mod modify;
 pub use modify::*;
+
+mod cell;
+pub use cell::*;
@@ -2,7 +2,22 @@ use std::ops::{Deref, DerefMut};
 use crate::Resetable;
 
 /// Struct that holds value and tracks if it was modified.
-pub struct Modified<T>(pub(crate) T, pub(crate) bool);
+///
+/// Alongside the boolean dirty flag the value carries a monotonically increasing
+/// change tick that is bumped on every tracked mutation (through [`DerefMut`],
+/// `set`, `set_if_modified` or `set_modified`). Writes made through `bypass` or
+/// `map_unchanged`, and drains by `Resetable::take_modified`, leave the tick as
+/// it is. A [`Snapshot`] taken with
+/// [`Modified::snapshot`] lets independent observers each ask whether the value
+/// changed since *they* last looked, regardless of resets made for anyone else.
+pub struct Modified<T>(pub(crate) T, pub(crate) bool, pub(crate) u32);
+
+/// Opaque token capturing a [`Modified`] change tick at a point in time.
+///
+/// Obtain one with [`Modified::snapshot`] and later pass it to
+/// [`Modified::changed_since`] to detect mutations made after it was taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot(pub(crate) u32);
 
 impl<T> Modified<T> {
     /// Creates new [`Modified`] with `v` inside.
@@ -13,7 +28,7 @@ impl<T> Modified<T> {
     /// ```
     #[inline]
     pub fn new(v: T) -> Self {
-        Self(v, false)
+        Self(v, false, 0)
     }
 
     /// Creates new [`Modified`] with `v` inside and marks it as it was modified.
@@ -24,7 +39,7 @@ impl<T> Modified<T> {
     /// ```
     #[inline]
     pub fn new_modified(v: T) -> Self {
-        Self(v, true)
+        Self(v, true, 0)
     }
 
     /// Destroys previous valus inside [`Modified`] replacing it with the new one.
@@ -39,6 +54,63 @@ impl<T> Modified<T> {
         **self = v;
     }
 
+    /// Replaces the inner value with `v`, but only marks it as modified when
+    /// `v` actually differs from the current value.
+    /// Returns `true` if the value was changed, otherwise `false`.
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// assert!(!m.set_if_modified(15));
+    /// assert!(m.is_unchanged());
+    ///
+    /// assert!(m.set_if_modified(20));
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn set_if_modified(&mut self, v: T) -> bool
+    where
+        T: PartialEq
+    {
+        if self.0 != v {
+            self.0 = v;
+            self.1 = true;
+            self.2 = self.2.wrapping_add(1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns a mutable reference to the inner value without marking it as modified.
+    /// Unlike [`DerefMut`], writes through this reference do not flip the dirty bit,
+    /// which is useful for internal bookkeeping or deserialization fill-in.
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// *m.bypass() = 20;
+    /// assert_eq!(*m, 20);
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn bypass(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    /// Applies `f` to the inner value through [`bypass`], leaving the dirty bit untouched.
+    ///
+    /// [`bypass`]: Self::bypass
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// m.map_unchanged(|v| *v += 5);
+    /// assert_eq!(*m, 20);
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn map_unchanged(&mut self, f: impl FnOnce(&mut T)) {
+        f(self.bypass());
+    }
+
     /// Returns a reference to the inner value.
     /// ```
     /// # use modified::Modified;
@@ -89,6 +161,33 @@ impl<T> Modified<T> {
         (self.0, self.1)
     }
 
+    /// Forcibly marks the value as modified without going through [`DerefMut`].
+    /// Useful when the value changed in a way the tracker can't observe.
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// m.set_modified();
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn set_modified(&mut self) {
+        self.1 = true;
+        self.2 = self.2.wrapping_add(1);
+    }
+
+    /// Clears the dirty bit, acknowledging the change in place.
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// *m = 20;
+    /// m.set_unmodified();
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn set_unmodified(&mut self) {
+        self.1 = false;
+    }
+
     /// Returns `true` if the variable inside was modified, otherwise returns `false`.
     /// ```
     /// # use modified::Modified;
@@ -111,6 +210,37 @@ impl<T> Modified<T> {
     pub fn is_unchanged(&self) -> bool {
         !self.1
     }
+
+    /// Captures the current change tick as a [`Snapshot`] token.
+    /// Hand it to [`changed_since`] later to detect mutations made after this point,
+    /// independent of any [`reset`](crate::Resetable::reset) done for other observers.
+    ///
+    /// [`changed_since`]: Self::changed_since
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// let s = m.snapshot();
+    /// *m = 20;
+    /// assert!(m.changed_since(&s));
+    /// ```
+    #[inline]
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.2)
+    }
+
+    /// Returns `true` if the change tick was bumped after `snapshot` was taken.
+    /// ```
+    /// # use modified::Modified;
+    /// let mut m = Modified::new(15);
+    /// let s = m.snapshot();
+    /// assert!(!m.changed_since(&s));
+    /// *m = 20;
+    /// assert!(m.changed_since(&s));
+    /// ```
+    #[inline]
+    pub fn changed_since(&self, snapshot: &Snapshot) -> bool {
+        self.2 != snapshot.0
+    }
 }
 
 impl<T> Default for Modified<T>
@@ -120,7 +250,7 @@ where
     /// Creates new Modified from default value of `T`.
     #[inline]
     fn default() -> Self {
-        Self(T::default(), false)
+        Self(T::default(), false, 0)
     }
 }
 
@@ -131,7 +261,7 @@ where
     /// Creates new [`Modified`] from the default value of `T` and marks it as it was modified.
     #[inline]
     pub fn default_modified() -> Self {
-        Self(T::default(), true)
+        Self(T::default(), true, 0)
     }
 }
 
@@ -143,7 +273,7 @@ where
     /// That means that if value was changed, cloned will also be marked as changed.
     #[inline]
     fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1)
+        Self(self.0.clone(), self.1, self.2)
     }
 }
 
@@ -167,6 +297,7 @@ impl<T> DerefMut for Modified<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.1 = true;
+        self.2 = self.2.wrapping_add(1);
         &mut self.0
     }
 }
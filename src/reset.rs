@@ -44,7 +44,39 @@ impl<T> Resetable<T> {
     pub fn reset(&mut self) {
         self.0.1 = false;
     }
-    
+
+    /// Clears the dirty bit, acknowledging the change in place.
+    /// Behaves like [`reset`] and mirrors [`Modified::set_unmodified`].
+    ///
+    /// [`reset`]: Self::reset
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut r = Resetable::new(15);
+    /// *r = 20;
+    /// r.set_unmodified();
+    /// assert!(r.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn set_unmodified(&mut self) {
+        self.0.set_unmodified();
+    }
+
+    /// Resets the internal state and reports whether it was modified beforehand,
+    /// matching the `changed()`-then-`reset()` idiom in one call.
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut r = Resetable::new(15);
+    /// *r = 20;
+    /// assert!(r.reset_and_was_modified());
+    /// assert!(!r.reset_and_was_modified());
+    /// ```
+    #[inline]
+    pub fn reset_and_was_modified(&mut self) -> bool {
+        let was = self.0.1;
+        self.0.1 = false;
+        was
+    }
+
     /// Destroys previous valus inside [`Resetable`] replacing it with the new one.
     /// ```
     /// # use modified::Resetable;
@@ -57,6 +89,56 @@ impl<T> Resetable<T> {
         **self = v;
     }
 
+    /// Replaces the inner value with `v`, but only marks it as modified when
+    /// `v` actually differs from the current value.
+    /// Returns `true` if the value was changed, otherwise `false`.
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut m = Resetable::new(15);
+    /// assert!(!m.set_if_modified(15));
+    /// assert!(m.is_unchanged());
+    ///
+    /// assert!(m.set_if_modified(20));
+    /// assert!(m.is_modified());
+    /// ```
+    #[inline]
+    pub fn set_if_modified(&mut self, v: T) -> bool
+    where
+        T: PartialEq
+    {
+        self.0.set_if_modified(v)
+    }
+
+    /// Returns a mutable reference to the inner value without marking it as modified.
+    /// Unlike [`DerefMut`], writes through this reference do not flip the dirty bit,
+    /// which is useful for internal bookkeeping or resetting derived fields.
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut m = Resetable::new(15);
+    /// *m.bypass() = 20;
+    /// assert_eq!(*m, 20);
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn bypass(&mut self) -> &mut T {
+        self.0.bypass()
+    }
+
+    /// Applies `f` to the inner value through [`bypass`], leaving the dirty bit untouched.
+    ///
+    /// [`bypass`]: Self::bypass
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut m = Resetable::new(15);
+    /// m.map_unchanged(|v| *v += 5);
+    /// assert_eq!(*m, 20);
+    /// assert!(m.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn map_unchanged(&mut self, f: impl FnOnce(&mut T)) {
+        self.0.map_unchanged(f);
+    }
+
     /// Returns a reference to the inner value.
     /// ```
     /// # use modified::Resetable;
@@ -129,6 +211,39 @@ impl<T> Resetable<T> {
     pub fn is_unchanged(&self) -> bool {
         !self.0.1
     }
+
+    /// Captures the current change tick as a [`Snapshot`](crate::Snapshot) token.
+    /// Because the tick is independent of [`reset`], observers holding a snapshot
+    /// keep tracking changes even after the dirty flag is cleared for someone else.
+    ///
+    /// [`reset`]: Self::reset
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut r = Resetable::new(15);
+    /// let s = r.snapshot();
+    /// *r = 20;
+    /// r.reset();
+    /// // The reset cleared the flag, but our snapshot still sees the change.
+    /// assert!(r.changed_since(&s));
+    /// ```
+    #[inline]
+    pub fn snapshot(&self) -> crate::Snapshot {
+        self.0.snapshot()
+    }
+
+    /// Returns `true` if the change tick was bumped after `snapshot` was taken.
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut r = Resetable::new(15);
+    /// let s = r.snapshot();
+    /// assert!(!r.changed_since(&s));
+    /// *r = 20;
+    /// assert!(r.changed_since(&s));
+    /// ```
+    #[inline]
+    pub fn changed_since(&self, snapshot: &crate::Snapshot) -> bool {
+        self.0.changed_since(snapshot)
+    }
 }
 
 
@@ -152,6 +267,31 @@ where
     pub fn default_modified() -> Self {
         Self(Modified::default_modified())
     }
+
+    /// Drains the value out when it was modified, replacing it with `T::default()`
+    /// and clearing the dirty flag in one step. Returns `None` when unchanged.
+    ///
+    /// This is the natural primitive for change-driven pipelines: "give me the new
+    /// value to flush downstream, but only if there's something new".
+    /// ```
+    /// # use modified::Resetable;
+    /// let mut r = Resetable::new(15);
+    /// assert_eq!(r.take_modified(), None);
+    ///
+    /// *r = 20;
+    /// assert_eq!(r.take_modified(), Some(20));
+    /// assert_eq!(*r, 0);
+    /// assert!(r.is_unchanged());
+    /// ```
+    #[inline]
+    pub fn take_modified(&mut self) -> Option<T> {
+        if self.0.1 {
+            self.0.1 = false;
+            Some(std::mem::take(&mut self.0.0))
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> Clone for Resetable<T>